@@ -0,0 +1,136 @@
+use petgraph::dot::{Config, Dot};
+use petgraph::graph::{Graph, NodeIndex};
+use petgraph::Undirected;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, Write};
+
+/// A small qualitative palette, cycled through when the number of degree
+/// buckets or communities exceeds its length.
+const PALETTE: [&str; 8] = [
+    "#e41a1c", "#377eb8", "#4daf4a", "#984ea3", "#ff7f00", "#ffff33", "#a65628", "#f781bf",
+];
+
+/// Controls how [`write_dot`] colors and sizes nodes in the exported graph.
+#[derive(Default)]
+pub struct DotOptions {
+    /// Scale node size by degree (higher-degree nodes are drawn larger).
+    pub size_by_degree: bool,
+    /// Color nodes by degree bucket.
+    pub color_by_degree: bool,
+    /// Tint nodes by community: maps a node's label to a circle/community id.
+    /// Ignored for any node it doesn't cover. Takes priority over `color_by_degree`.
+    pub communities: Option<HashMap<String, usize>>,
+}
+
+/// Writes `graph` to `path` as a Graphviz DOT file, suitable for rendering with
+/// Graphviz or importing into Gephi. Node labels are the original string ids
+/// carried as node weights; `opts` controls optional degree/community styling.
+pub fn write_dot(
+    graph: &Graph<String, (), Undirected>,
+    path: &str,
+    opts: &DotOptions,
+) -> io::Result<()> {
+    let degree: HashMap<NodeIndex, usize> = graph
+        .node_indices()
+        .map(|n| (n, graph.neighbors(n).count()))
+        .collect();
+
+    let get_node_attrs = |_g: &Graph<String, (), Undirected>, node: (NodeIndex, &String)| {
+        let (idx, label) = node;
+        let mut attrs = vec![format!("label=\"{}\"", label)];
+
+        if opts.size_by_degree {
+            let d = degree.get(&idx).copied().unwrap_or(0);
+            attrs.push(format!("width={:.2}", 0.3 + d as f64 * 0.05));
+        }
+
+        let fill_color = if let Some(communities) = &opts.communities {
+            communities.get(label).map(|&c| PALETTE[c % PALETTE.len()])
+        } else if opts.color_by_degree {
+            let d = degree.get(&idx).copied().unwrap_or(0);
+            Some(PALETTE[d % PALETTE.len()])
+        } else {
+            None
+        };
+
+        if let Some(color) = fill_color {
+            attrs.push("style=filled".to_string());
+            attrs.push(format!("fillcolor=\"{}\"", color));
+        }
+
+        attrs.join(", ")
+    };
+
+    let dot = Dot::with_attr_getters(
+        graph,
+        &[Config::NodeNoLabel, Config::EdgeNoLabel],
+        &|_, _| String::new(),
+        &get_node_attrs,
+    );
+
+    let mut file = File::create(path)?;
+    write!(file, "{:?}", dot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_dot_colors_by_degree_and_size() {
+        // A 3-node path 0-1-2: node 1 has degree 2, nodes 0 and 2 have degree 1.
+        let mut graph = Graph::<String, (), Undirected>::new_undirected();
+        let n0 = graph.add_node("0".to_string());
+        let n1 = graph.add_node("1".to_string());
+        let n2 = graph.add_node("2".to_string());
+        graph.add_edge(n0, n1, ());
+        graph.add_edge(n1, n2, ());
+
+        let file_path = "test_degree.dot";
+        let opts = DotOptions {
+            size_by_degree: true,
+            color_by_degree: true,
+            communities: None,
+        };
+        write_dot(&graph, file_path, &opts).unwrap();
+        let contents = std::fs::read_to_string(file_path).unwrap();
+
+        assert!(contents.contains("label=\"0\""));
+        assert!(contents.contains("label=\"1\""));
+        assert!(contents.contains("label=\"2\""));
+        assert!(!contents.contains("\\\"0\\\""), "label should not be double-escaped");
+        assert!(contents.contains(&format!("fillcolor=\"{}\"", PALETTE[2 % PALETTE.len()])));
+        assert!(contents.contains(&format!("fillcolor=\"{}\"", PALETTE[1 % PALETTE.len()])));
+        assert!(contents.contains("width=0.40")); // degree-2 node: 0.3 + 2*0.05
+
+        std::fs::remove_file(file_path).unwrap();
+    }
+
+    #[test]
+    fn test_write_dot_colors_by_community() {
+        let mut graph = Graph::<String, (), Undirected>::new_undirected();
+        let n0 = graph.add_node("0".to_string());
+        let n1 = graph.add_node("1".to_string());
+        graph.add_edge(n0, n1, ());
+
+        let mut communities = HashMap::new();
+        communities.insert("0".to_string(), 0usize);
+        communities.insert("1".to_string(), 1usize);
+
+        let file_path = "test_community.dot";
+        let opts = DotOptions {
+            size_by_degree: false,
+            color_by_degree: false,
+            communities: Some(communities),
+        };
+        write_dot(&graph, file_path, &opts).unwrap();
+        let contents = std::fs::read_to_string(file_path).unwrap();
+
+        assert!(contents.contains(&format!("fillcolor=\"{}\"", PALETTE[0])));
+        assert!(contents.contains(&format!("fillcolor=\"{}\"", PALETTE[1])));
+        assert!(!contents.contains("width="));
+
+        std::fs::remove_file(file_path).unwrap();
+    }
+}
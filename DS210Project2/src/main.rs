@@ -5,6 +5,8 @@ use std::collections::HashMap;
 use std::fs::File;
 use std::io::{self, BufRead};
 use std::path::Path;
+mod dot;
+mod path;
 mod stats;
 
 fn read_file(file_path: &str) -> io::Result<Vec<String>> {
@@ -15,7 +17,7 @@ fn read_file(file_path: &str) -> io::Result<Vec<String>> {
 
 
 // Load edges into a graph
-fn load_edges(file_path: &str) -> Graph<(), (), Undirected> {
+fn load_edges(file_path: &str) -> Graph<String, (), Undirected> {
     let mut graph = Graph::new_undirected();
     let mut node_map = HashMap::new();
 
@@ -24,20 +26,102 @@ fn load_edges(file_path: &str) -> Graph<(), (), Undirected> {
         let line = line.expect("Failed to read line");
         let nodes: Vec<&str> = line.split_whitespace().collect();
         if nodes.len() == 2 {
-            let u = *node_map.entry(nodes[0].to_string()).or_insert_with(|| graph.add_node(()));
-            let v = *node_map.entry(nodes[1].to_string()).or_insert_with(|| graph.add_node(()));
+            let u = *node_map.entry(nodes[0].to_string())
+                .or_insert_with(|| graph.add_node(nodes[0].to_string()));
+            let v = *node_map.entry(nodes[1].to_string())
+                .or_insert_with(|| graph.add_node(nodes[1].to_string()));
             graph.add_edge(u, v, ());
         }
     }
     graph
 }
 
+/// Loads an edge list into a weighted graph, parsing an optional third
+/// whitespace-separated column as the edge weight (default 1.0), so existing
+/// two-column `.edges` files still load.
+fn load_weighted_edges(file_path: &str) -> Graph<String, f64, Undirected> {
+    let mut graph = Graph::new_undirected();
+    let mut node_map = HashMap::new();
+
+    let file = File::open(file_path).expect("Failed to open edges file");
+    for line in io::BufReader::new(file).lines() {
+        let line = line.expect("Failed to read line");
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let weight = match fields.len() {
+            2 => Some(1.0),
+            3 => Some(fields[2].parse().unwrap_or_else(|_| {
+                eprintln!(
+                    "Warning: unparsable weight \"{}\" on edge {} {}, defaulting to 1.0",
+                    fields[2], fields[0], fields[1]
+                );
+                1.0
+            })),
+            _ => None,
+        };
+        if let Some(weight) = weight {
+            let u = *node_map
+                .entry(fields[0].to_string())
+                .or_insert_with(|| graph.add_node(fields[0].to_string()));
+            let v = *node_map
+                .entry(fields[1].to_string())
+                .or_insert_with(|| graph.add_node(fields[1].to_string()));
+            graph.add_edge(u, v, weight);
+        }
+    }
+    graph
+}
+
 fn count_lines(file_path: &str) -> io::Result<usize> {
     let file = File::open(file_path)?;
     let reader = io::BufReader::new(file);
     Ok(reader.lines().count())
 }
 
+/// Parses a `.circles` file into a map from circle name to its member node ids.
+/// Each line is `<circle name> <member id> <member id> ...`.
+fn load_circles(file_path: &str) -> io::Result<HashMap<String, Vec<String>>> {
+    let file = File::open(file_path)?;
+    let mut circles = HashMap::new();
+
+    for line in io::BufReader::new(file).lines() {
+        let line = line?;
+        let mut parts = line.split_whitespace();
+        if let Some(name) = parts.next() {
+            circles.insert(name.to_string(), parts.map(String::from).collect());
+        }
+    }
+
+    Ok(circles)
+}
+
+/// Parses a `.feat` file into a map from node id to its binary feature vector.
+/// Each line is `<node id> <feature bit> <feature bit> ...`.
+fn load_features(file_path: &str) -> io::Result<HashMap<String, Vec<u8>>> {
+    let file = File::open(file_path)?;
+    let mut features = HashMap::new();
+
+    for line in io::BufReader::new(file).lines() {
+        let line = line?;
+        let mut parts = line.split_whitespace();
+        if let Some(id) = parts.next() {
+            let vector = parts
+                .map(|bit| {
+                    bit.parse().unwrap_or_else(|_| {
+                        eprintln!(
+                            "Warning: unparsable feature bit \"{}\" for node {}, defaulting to 0",
+                            bit, id
+                        );
+                        0
+                    })
+                })
+                .collect();
+            features.insert(id.to_string(), vector);
+        }
+    }
+
+    Ok(features)
+}
+
 /// Analyzes `.edges`, `.circles`, and `.feat` files and returns their line counts.
 fn analyze_files(directory: &str) -> io::Result<HashMap<String, usize>> {
     let mut counts = HashMap::new();
@@ -59,7 +143,7 @@ fn analyze_files(directory: &str) -> io::Result<HashMap<String, usize>> {
 }
 
 // Calculate degree distribution
-fn calculate_degree_distribution(graph: &Graph<(), (), Undirected>) -> HashMap<usize, usize> {
+fn calculate_degree_distribution(graph: &Graph<String, (), Undirected>) -> HashMap<usize, usize> {
     let mut degree_counts = HashMap::new();
     for node in graph.node_indices() {
         let degree = graph.neighbors(node).count();
@@ -119,7 +203,92 @@ fn main() {
     let median_separation = crate::stats::calculate_median_separation(&graph);
     println!("Median separation: {:.2}", median_separation);
 
+    let component_report = crate::stats::analyze_components(&graph);
+    if component_report.is_disconnected() {
+        println!(
+            "Warning: graph is disconnected ({} components); the separation statistics \
+             above only cover pairs within the same component. The largest-component \
+             figures below are restricted to the largest component ({} of {} nodes).",
+            component_report.num_components,
+            component_report.largest_component_size,
+            graph.node_count()
+        );
+    }
+    println!("Number of components: {}", component_report.num_components);
+    println!("Largest component size: {}", component_report.largest_component_size);
+    println!("Largest component diameter: {}", component_report.largest_component_diameter);
+    println!(
+        "Largest component mean separation: {:.2}",
+        component_report.largest_component_stats.mean
+    );
+
+    let communities = match load_circles("0.circles") {
+        Ok(circles) => {
+            let report = crate::stats::modularity(&graph, &circles);
+            println!(
+                "Modularity: {:.4} (coverage: {:.1}%)",
+                report.modularity,
+                report.coverage * 100.0
+            );
+            Some(circle_membership(&circles))
+        }
+        Err(e) => {
+            eprintln!("Error loading circles: {}", e);
+            None
+        }
+    };
+
+    match load_features("0.feat") {
+        Ok(features) => println!("Loaded feature vectors for {} nodes", features.len()),
+        Err(e) => eprintln!("Error loading features: {}", e),
+    }
+
+    let closeness = crate::stats::closeness_centrality(&graph);
+    println!("Top 5 nodes by closeness centrality:");
+    for (label, score) in crate::stats::top_k_by_centrality(&graph, &closeness, 5) {
+        println!("  {}: {:.4}", label, score);
+    }
+
+    let betweenness = crate::stats::betweenness_centrality(&graph);
+    println!("Top 5 nodes by betweenness centrality:");
+    for (label, score) in crate::stats::top_k_by_centrality(&graph, &betweenness, 5) {
+        println!("  {}: {:.4}", label, score);
+    }
 
+    let dot_opts = crate::dot::DotOptions {
+        color_by_degree: communities.is_none(),
+        size_by_degree: true,
+        communities,
+    };
+    if let Err(e) = crate::dot::write_dot(&graph, "graph.dot", &dot_opts) {
+        eprintln!("Error writing DOT file: {}", e);
+    } else {
+        println!("Graph exported to graph.dot");
+    }
+
+    let weighted_graph = load_weighted_edges(file_path);
+    let labels: Vec<String> = weighted_graph.node_indices().map(|n| weighted_graph[n].clone()).collect();
+    if let [src, dst, ..] = labels.as_slice() {
+        match crate::path::shortest_path(&weighted_graph, src, dst) {
+            Some((cost, path)) => println!(
+                "Shortest path from {} to {}: cost {:.2}, path {:?}",
+                src, dst, cost, path
+            ),
+            None => println!("No path found between {} and {}", src, dst),
+        }
+    }
+}
+
+/// Flattens circle membership into a node id -> circle index map, for DOT coloring.
+/// A node in multiple circles is tinted by the first one encountered.
+fn circle_membership(circles: &HashMap<String, Vec<String>>) -> HashMap<String, usize> {
+    let mut membership = HashMap::new();
+    for (i, members) in circles.values().enumerate() {
+        for id in members {
+            membership.entry(id.clone()).or_insert(i);
+        }
+    }
+    membership
 }
 
 #[cfg(test)]
@@ -147,10 +316,10 @@ mod tests {
     #[test]
     fn test_calculate_degree_distribution() {
         // Create a graph with known degrees
-        let mut graph = Graph::<(), (), Undirected>::new_undirected();
-        let n0 = graph.add_node(()); // Node 0
-        let n1 = graph.add_node(()); // Node 1
-        let n2 = graph.add_node(()); // Node 2
+        let mut graph = Graph::<String, (), Undirected>::new_undirected();
+        let n0 = graph.add_node("0".to_string()); // Node 0
+        let n1 = graph.add_node("1".to_string()); // Node 1
+        let n2 = graph.add_node("2".to_string()); // Node 2
         graph.add_edge(n0, n1, ()); // Edge 0-1
         graph.add_edge(n1, n2, ()); // Edge 1-2
         graph.add_edge(n2, n0, ()); // Edge 2-0
@@ -164,10 +333,10 @@ mod tests {
     #[test]
     fn test_calculate_mean_separation() {
         // Create a simple triangle graph
-        let mut graph = Graph::<(), (), Undirected>::new_undirected();
-        let n0 = graph.add_node(()); // Node 0
-        let n1 = graph.add_node(()); // Node 1
-        let n2 = graph.add_node(()); // Node 2
+        let mut graph = Graph::<String, (), Undirected>::new_undirected();
+        let n0 = graph.add_node("0".to_string()); // Node 0
+        let n1 = graph.add_node("1".to_string()); // Node 1
+        let n2 = graph.add_node("2".to_string()); // Node 2
         graph.add_edge(n0, n1, ()); // Edge 0-1
         graph.add_edge(n1, n2, ()); // Edge 1-2
         graph.add_edge(n2, n0, ()); // Edge 2-0
@@ -181,11 +350,11 @@ mod tests {
     #[test]
     fn test_calculate_standard_deviation_separation() {
         // Create a simple graph
-        let mut graph = Graph::<(), (), Undirected>::new_undirected();
-        let n0 = graph.add_node(()); // Node 0
-        let n1 = graph.add_node(()); // Node 1
-        let n2 = graph.add_node(()); // Node 2
-        let n3 = graph.add_node(()); // Node 3
+        let mut graph = Graph::<String, (), Undirected>::new_undirected();
+        let n0 = graph.add_node("0".to_string()); // Node 0
+        let n1 = graph.add_node("1".to_string()); // Node 1
+        let n2 = graph.add_node("2".to_string()); // Node 2
+        let n3 = graph.add_node("3".to_string()); // Node 3
         graph.add_edge(n0, n1, ()); // Edge 0-1
         graph.add_edge(n1, n2, ()); // Edge 1-2
         graph.add_edge(n2, n3, ()); // Edge 2-3
@@ -199,11 +368,11 @@ mod tests {
     #[test]
     fn test_calculate_median_separation() {
         // Create a simple graph
-        let mut graph = Graph::<(), (), Undirected>::new_undirected();
-        let n0 = graph.add_node(()); // Node 0
-        let n1 = graph.add_node(()); // Node 1
-        let n2 = graph.add_node(()); // Node 2
-        let n3 = graph.add_node(()); // Node 3
+        let mut graph = Graph::<String, (), Undirected>::new_undirected();
+        let n0 = graph.add_node("0".to_string()); // Node 0
+        let n1 = graph.add_node("1".to_string()); // Node 1
+        let n2 = graph.add_node("2".to_string()); // Node 2
+        let n3 = graph.add_node("3".to_string()); // Node 3
         graph.add_edge(n0, n1, ()); // Edge 0-1
         graph.add_edge(n1, n2, ()); // Edge 1-2
         graph.add_edge(n2, n3, ()); // Edge 2-3
@@ -214,13 +383,196 @@ mod tests {
         assert_eq!(median, 1.5); // Median of [1, 1, 1, 2, 2, 3]
     }
 
+    #[test]
+    fn test_load_circles_parses_name_and_members() {
+        let file_path = "test_circles_ok.circles";
+        std::fs::write(file_path, "circle0 1 2 3\ncircle1 4\n").unwrap();
+
+        let circles = load_circles(file_path).unwrap();
+
+        assert_eq!(circles.len(), 2);
+        assert_eq!(
+            circles["circle0"],
+            vec!["1".to_string(), "2".to_string(), "3".to_string()]
+        );
+        assert_eq!(circles["circle1"], vec!["4".to_string()]);
+
+        std::fs::remove_file(file_path).unwrap();
+    }
+
+    #[test]
+    fn test_load_circles_name_with_no_members_is_empty_vec() {
+        let file_path = "test_circles_empty.circles";
+        std::fs::write(file_path, "circle0\n").unwrap();
+
+        let circles = load_circles(file_path).unwrap();
+
+        assert_eq!(circles.len(), 1);
+        assert!(circles["circle0"].is_empty());
+
+        std::fs::remove_file(file_path).unwrap();
+    }
+
+    #[test]
+    fn test_load_features_parses_bit_vector() {
+        let file_path = "test_features_ok.feat";
+        std::fs::write(file_path, "1 0 1 1 0\n").unwrap();
+
+        let features = load_features(file_path).unwrap();
+
+        assert_eq!(features["1"], vec![0, 1, 1, 0]);
+
+        std::fs::remove_file(file_path).unwrap();
+    }
+
+    #[test]
+    fn test_load_features_unparsable_bit_defaults_to_zero() {
+        let file_path = "test_features_bad.feat";
+        std::fs::write(file_path, "1 0 x 1\n").unwrap();
+
+        let features = load_features(file_path).unwrap();
+
+        // `x` fails to parse and defaults to 0 rather than shifting later bits.
+        assert_eq!(features["1"], vec![0, 0, 1]);
+
+        std::fs::remove_file(file_path).unwrap();
+    }
+
+    #[test]
+    fn test_load_weighted_edges_two_columns_default_to_unit_weight() {
+        let file_path = "test_two_col.edges";
+        std::fs::write(file_path, "1 2\n2 3\n").unwrap();
+
+        let graph = load_weighted_edges(file_path);
+
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.edge_count(), 2);
+        assert!(graph.edge_weights().all(|&w| w == 1.0));
+
+        std::fs::remove_file(file_path).unwrap();
+    }
+
+    #[test]
+    fn test_load_weighted_edges_three_columns_parses_weight() {
+        let file_path = "test_three_col.edges";
+        std::fs::write(file_path, "1 2 2.5\n2 3 0.5\n").unwrap();
+
+        let graph = load_weighted_edges(file_path);
+
+        let weights: Vec<f64> = graph.edge_weights().copied().collect();
+        assert_eq!(graph.edge_count(), 2);
+        assert!(weights.contains(&2.5));
+        assert!(weights.contains(&0.5));
+
+        std::fs::remove_file(file_path).unwrap();
+    }
+
+    #[test]
+    fn test_load_weighted_edges_unparsable_weight_defaults_to_one() {
+        let file_path = "test_bad_weight.edges";
+        std::fs::write(file_path, "1 2 not-a-number\n").unwrap();
+
+        let graph = load_weighted_edges(file_path);
+
+        // The edge is kept (not dropped) with a default weight of 1.0.
+        assert_eq!(graph.edge_count(), 1);
+        assert_eq!(*graph.edge_weights().next().unwrap(), 1.0);
+
+        std::fs::remove_file(file_path).unwrap();
+    }
+
+    #[test]
+    fn test_modularity_whole_graph_circle_is_zero() {
+        // A triangle graph with a single circle covering every node is the trivial
+        // "everything in one community" partition, whose modularity is always 0.
+        let mut graph = Graph::<String, (), Undirected>::new_undirected();
+        let n0 = graph.add_node("0".to_string()); // Node 0
+        let n1 = graph.add_node("1".to_string()); // Node 1
+        let n2 = graph.add_node("2".to_string()); // Node 2
+        graph.add_edge(n0, n1, ()); // Edge 0-1
+        graph.add_edge(n1, n2, ()); // Edge 1-2
+        graph.add_edge(n2, n0, ()); // Edge 2-0
+
+        let mut circles = HashMap::new();
+        circles.insert(
+            "circle0".to_string(),
+            vec!["0".to_string(), "1".to_string(), "2".to_string()],
+        );
+
+        let report = crate::stats::modularity(&graph, &circles);
+
+        assert!((report.modularity - 0.0).abs() < 1e-9);
+        assert_eq!(report.coverage, 1.0);
+    }
+
+    #[test]
+    fn test_analyze_components() {
+        // Two components: a 3-node triangle and a single isolated node.
+        let mut graph = Graph::<String, (), Undirected>::new_undirected();
+        let n0 = graph.add_node("0".to_string()); // Node 0
+        let n1 = graph.add_node("1".to_string()); // Node 1
+        let n2 = graph.add_node("2".to_string()); // Node 2
+        graph.add_node("3".to_string()); // Node 3, isolated
+        graph.add_edge(n0, n1, ()); // Edge 0-1
+        graph.add_edge(n1, n2, ()); // Edge 1-2
+        graph.add_edge(n2, n0, ()); // Edge 2-0
+
+        let report = crate::stats::analyze_components(&graph);
+
+        assert_eq!(report.num_components, 2);
+        assert_eq!(report.largest_component_size, 3);
+        assert_eq!(report.largest_component_diameter, 1);
+        assert!(report.is_disconnected());
+    }
+
+    #[test]
+    fn test_betweenness_centrality_star() {
+        // A 4-node star: every shortest path between two leaves runs through the
+        // center, so the center's betweenness is 3.0 (one for each leaf pair) and
+        // every leaf's is 0.0.
+        let mut graph = Graph::<String, (), Undirected>::new_undirected();
+        let center = graph.add_node("center".to_string());
+        let a = graph.add_node("a".to_string());
+        let b = graph.add_node("b".to_string());
+        let c = graph.add_node("c".to_string());
+        graph.add_edge(center, a, ());
+        graph.add_edge(center, b, ());
+        graph.add_edge(center, c, ());
+
+        let betweenness = crate::stats::betweenness_centrality(&graph);
+
+        assert!((betweenness[&center] - 3.0).abs() < 1e-9);
+        assert!((betweenness[&a] - 0.0).abs() < 1e-9);
+        assert!((betweenness[&b] - 0.0).abs() < 1e-9);
+        assert!((betweenness[&c] - 0.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_closeness_centrality_path() {
+        // A 3-node path 0-1-2: the middle node reaches both others at distance 1
+        // (closeness 1.0), while each end reaches the other at distance 2 through
+        // the middle (closeness 2/3).
+        let mut graph = Graph::<String, (), Undirected>::new_undirected();
+        let n0 = graph.add_node("0".to_string());
+        let n1 = graph.add_node("1".to_string());
+        let n2 = graph.add_node("2".to_string());
+        graph.add_edge(n0, n1, ());
+        graph.add_edge(n1, n2, ());
+
+        let closeness = crate::stats::closeness_centrality(&graph);
+
+        assert!((closeness[&n1] - 1.0).abs() < 1e-9);
+        assert!((closeness[&n0] - 2.0 / 3.0).abs() < 1e-9);
+        assert!((closeness[&n2] - 2.0 / 3.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_print_sorted_degrees() {
         // Create a graph
-        let mut graph = Graph::<(), (), Undirected>::new_undirected();
-        let n0 = graph.add_node(()); // Node 0
-        let n1 = graph.add_node(()); // Node 1
-        let n2 = graph.add_node(()); // Node 2
+        let mut graph = Graph::<String, (), Undirected>::new_undirected();
+        let n0 = graph.add_node("0".to_string()); // Node 0
+        let n1 = graph.add_node("1".to_string()); // Node 1
+        let n2 = graph.add_node("2".to_string()); // Node 2
         graph.add_edge(n0, n1, ()); // Edge 0-1
         graph.add_edge(n1, n2, ()); // Edge 1-2
 
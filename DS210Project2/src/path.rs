@@ -0,0 +1,68 @@
+use petgraph::algo::astar;
+use petgraph::graph::{Graph, NodeIndex};
+use petgraph::Undirected;
+use std::collections::HashMap;
+
+/// Finds the lowest-cost path between two labeled nodes. Runs `petgraph::algo::astar`
+/// with a zero heuristic, which makes it equivalent to Dijkstra and therefore assumes
+/// non-negative edge weights. Returns the total cost and the path as original node
+/// labels, or `None` if either label is unknown or no path exists.
+pub fn shortest_path(
+    graph: &Graph<String, f64, Undirected>,
+    src_label: &str,
+    dst_label: &str,
+) -> Option<(f64, Vec<String>)> {
+    let label_to_index: HashMap<&str, NodeIndex> =
+        graph.node_indices().map(|n| (graph[n].as_str(), n)).collect();
+
+    let &src = label_to_index.get(src_label)?;
+    let &dst = label_to_index.get(dst_label)?;
+
+    let (cost, path) = astar(graph, src, |node| node == dst, |edge| *edge.weight(), |_| 0.0)?;
+
+    Some((cost, path.into_iter().map(|n| graph[n].clone()).collect()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shortest_path_sums_weights_along_cheapest_route() {
+        // 0 --5.0-- 1 --1.0-- 2, and a direct 0--2 edge of 10.0: the cheapest
+        // route from 0 to 2 goes through 1 at cost 6.0, not the direct edge.
+        let mut graph = Graph::<String, f64, Undirected>::new_undirected();
+        let n0 = graph.add_node("0".to_string());
+        let n1 = graph.add_node("1".to_string());
+        let n2 = graph.add_node("2".to_string());
+        graph.add_edge(n0, n1, 5.0);
+        graph.add_edge(n1, n2, 1.0);
+        graph.add_edge(n0, n2, 10.0);
+
+        let (cost, path) = shortest_path(&graph, "0", "2").unwrap();
+
+        assert_eq!(cost, 6.0);
+        assert_eq!(path, vec!["0".to_string(), "1".to_string(), "2".to_string()]);
+    }
+
+    #[test]
+    fn test_shortest_path_unknown_label_returns_none() {
+        let mut graph = Graph::<String, f64, Undirected>::new_undirected();
+        let n0 = graph.add_node("0".to_string());
+        let n1 = graph.add_node("1".to_string());
+        graph.add_edge(n0, n1, 1.0);
+
+        assert!(shortest_path(&graph, "0", "nonexistent").is_none());
+        assert!(shortest_path(&graph, "nonexistent", "1").is_none());
+    }
+
+    #[test]
+    fn test_shortest_path_no_path_returns_none() {
+        // Two disconnected nodes: no edge between them at all.
+        let mut graph = Graph::<String, f64, Undirected>::new_undirected();
+        graph.add_node("0".to_string());
+        graph.add_node("1".to_string());
+
+        assert!(shortest_path(&graph, "0", "1").is_none());
+    }
+}
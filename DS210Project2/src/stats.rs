@@ -1,91 +1,321 @@
-use petgraph::algo::dijkstra;
 use petgraph::graph::{Graph, NodeIndex};
 use petgraph::Undirected;
+use std::collections::{HashMap, HashSet, VecDeque};
 
-pub fn calculate_mean_separation(graph: &Graph<(), (), Undirected>) -> f64 {
-    let mut total_distance = 0.0;
-    let mut pair_count = 0;
+/// Aggregate "degrees of separation" statistics over all reachable node pairs.
+pub struct SeparationStats {
+    pub mean: f64,
+    pub std_dev: f64,
+    pub median: f64,
+}
+
+/// Breadth-first shortest-path distances from `start`, since every edge has unit weight.
+/// Indexed by `node.index()`; unreached nodes are `None`.
+fn bfs_distances(graph: &Graph<String, (), Undirected>, start: NodeIndex) -> Vec<Option<u32>> {
+    let mut distance = vec![None; graph.node_count()];
+    let mut frontier = VecDeque::new();
 
-    for start_node in graph.node_indices() {
-        // Use Dijkstra's algorithm to calculate shortest paths from the start_node
-        let distances = dijkstra(graph, start_node, None, |_| 1);
+    distance[start.index()] = Some(0);
+    frontier.push_back(start);
 
-        for &distance in distances.values() {
-            if distance > 0 {
-                total_distance += distance as f64;
-                pair_count += 1;
+    while let Some(u) = frontier.pop_front() {
+        let dist_u = distance[u.index()].unwrap();
+        for v in graph.neighbors(u) {
+            if distance[v.index()].is_none() {
+                distance[v.index()] = Some(dist_u + 1);
+                frontier.push_back(v);
             }
         }
     }
 
-    if pair_count == 0 {
-        return 0.0; // Avoid division by zero
-    }
+    distance
+}
 
-    total_distance / pair_count as f64
+/// Computes mean, standard deviation, and median separation with a single BFS sweep
+/// from every node, instead of running Dijkstra three separate times.
+pub fn compute_separation_stats(graph: &Graph<String, (), Undirected>) -> SeparationStats {
+    let all_nodes: Vec<NodeIndex> = graph.node_indices().collect();
+    compute_separation_stats_for(graph, &all_nodes)
 }
 
-pub fn calculate_standard_deviation_separation(graph: &Graph<(), (), Undirected>) -> f64 {
+/// Same as [`compute_separation_stats`], but only BFS's from the given start nodes.
+/// Useful for restricting the statistics to a single connected component.
+pub fn compute_separation_stats_for(
+    graph: &Graph<String, (), Undirected>,
+    start_nodes: &[NodeIndex],
+) -> SeparationStats {
+    let mut sum = 0.0;
+    let mut sum_sq = 0.0;
+    let mut count = 0u64;
     let mut distances = vec![];
 
-    // Collect all shortest path lengths
-    for start_node in graph.node_indices() {
-        let shortest_paths = dijkstra(graph, start_node, None, |_| 1);
-        for &distance in shortest_paths.values() {
-            if distance > 0 {
-                distances.push(distance as f64);
+    for &start_node in start_nodes {
+        let dist = bfs_distances(graph, start_node);
+        for d in dist.into_iter().flatten() {
+            if d > 0 {
+                let d = d as f64;
+                sum += d;
+                sum_sq += d * d;
+                count += 1;
+                distances.push(d);
             }
         }
     }
 
-    // If there are no distances, standard deviation is undefined
-    if distances.is_empty() {
-        return 0.0;
+    if count == 0 {
+        return SeparationStats { mean: 0.0, std_dev: 0.0, median: 0.0 };
+    }
+
+    let mean = sum / count as f64;
+    let variance = sum_sq / count as f64 - mean * mean;
+    let std_dev = variance.max(0.0).sqrt();
+
+    distances.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mid = distances.len() / 2;
+    let median = if distances.len() % 2 == 0 {
+        (distances[mid - 1] + distances[mid]) / 2.0
+    } else {
+        distances[mid]
+    };
+
+    SeparationStats { mean, std_dev, median }
+}
+
+pub fn calculate_mean_separation(graph: &Graph<String, (), Undirected>) -> f64 {
+    compute_separation_stats(graph).mean
+}
+
+pub fn calculate_standard_deviation_separation(graph: &Graph<String, (), Undirected>) -> f64 {
+    compute_separation_stats(graph).std_dev
+}
+
+pub fn calculate_median_separation(graph: &Graph<String, (), Undirected>) -> f64 {
+    compute_separation_stats(graph).median
+}
+
+/// Labels every node with the connected component it belongs to, via BFS flood fill.
+/// Returns the components sorted from largest to smallest.
+pub fn find_components(graph: &Graph<String, (), Undirected>) -> Vec<Vec<NodeIndex>> {
+    let mut visited = vec![false; graph.node_count()];
+    let mut components = vec![];
+
+    for node in graph.node_indices() {
+        if visited[node.index()] {
+            continue;
+        }
+
+        let mut component = vec![];
+        let mut frontier = VecDeque::new();
+        visited[node.index()] = true;
+        frontier.push_back(node);
+
+        while let Some(u) = frontier.pop_front() {
+            component.push(u);
+            for v in graph.neighbors(u) {
+                if !visited[v.index()] {
+                    visited[v.index()] = true;
+                    frontier.push_back(v);
+                }
+            }
+        }
+
+        components.push(component);
     }
 
-    // Calculate mean
-    let mean: f64 = distances.iter().sum::<f64>() / distances.len() as f64;
+    components.sort_by_key(|component| std::cmp::Reverse(component.len()));
+    components
+}
 
-    // Calculate variance
-    let variance: f64 = distances
+/// Exact diameter (the longest shortest path) over the given nodes, via all-pairs BFS.
+pub fn compute_diameter_for(graph: &Graph<String, (), Undirected>, nodes: &[NodeIndex]) -> u32 {
+    nodes
         .iter()
-        .map(|&distance| (distance - mean).powi(2))
-        .sum::<f64>()
-        / distances.len() as f64;
+        .flat_map(|&start| bfs_distances(graph, start).into_iter().flatten())
+        .max()
+        .unwrap_or(0)
+}
 
-    // Standard deviation is the square root of variance
-    variance.sqrt()
+/// Connectivity summary of a graph: how many components it has, and separation
+/// statistics plus exact diameter restricted to the largest one.
+pub struct ComponentReport {
+    pub num_components: usize,
+    pub largest_component_size: usize,
+    pub largest_component_stats: SeparationStats,
+    pub largest_component_diameter: u32,
 }
 
-pub fn calculate_median_separation(graph: &Graph<(), (), Undirected>) -> f64 {
-    let mut distances = vec![];
+impl ComponentReport {
+    /// Whether the graph has more than one component, i.e. whether the largest
+    /// component's statistics are a restriction of the whole graph rather than
+    /// covering it exactly.
+    pub fn is_disconnected(&self) -> bool {
+        self.num_components > 1
+    }
+}
+
+/// Runs connected-components analysis and computes separation statistics plus exact
+/// diameter restricted to the largest component. Callers should check
+/// `is_disconnected()` before presenting the largest-component numbers as global ones.
+pub fn analyze_components(graph: &Graph<String, (), Undirected>) -> ComponentReport {
+    let components = find_components(graph);
+    let largest = components.first().cloned().unwrap_or_default();
 
-    // Collect all shortest path lengths
-    for start_node in graph.node_indices() {
-        let shortest_paths = dijkstra(graph, start_node, None, |_| 1);
-        for &distance in shortest_paths.values() {
-            if distance > 0 {
-                distances.push(distance as f64);
+    ComponentReport {
+        num_components: components.len(),
+        largest_component_size: largest.len(),
+        largest_component_stats: compute_separation_stats_for(graph, &largest),
+        largest_component_diameter: compute_diameter_for(graph, &largest),
+    }
+}
+
+/// How well a ground-truth circle partition explains the graph's actual edges.
+pub struct ModularityReport {
+    pub modularity: f64,
+    /// Fraction of graph nodes that belong to at least one circle.
+    pub coverage: f64,
+}
+
+/// Evaluates the community structure of `circles` (circle name -> member node ids)
+/// against `graph`. Nodes may belong to multiple circles or none, so modularity is
+/// computed only over the internal pairs that circles actually cover; `coverage`
+/// reports what fraction of nodes that represents.
+pub fn modularity(
+    graph: &Graph<String, (), Undirected>,
+    circles: &HashMap<String, Vec<String>>,
+) -> ModularityReport {
+    let label_to_index: HashMap<&String, NodeIndex> =
+        graph.node_indices().map(|n| (&graph[n], n)).collect();
+
+    let degree: HashMap<NodeIndex, usize> = graph
+        .node_indices()
+        .map(|n| (n, graph.neighbors(n).count()))
+        .collect();
+
+    let two_m = 2.0 * graph.edge_count() as f64;
+    if two_m == 0.0 {
+        return ModularityReport { modularity: 0.0, coverage: 0.0 };
+    }
+
+    let mut total = 0.0;
+    let mut covered = HashSet::new();
+
+    for members in circles.values() {
+        let indices: Vec<NodeIndex> = members
+            .iter()
+            .filter_map(|id| label_to_index.get(id).copied())
+            .collect();
+        covered.extend(indices.iter().copied());
+
+        // The textbook sum runs over every ordered pair (i, j) in the community,
+        // including i == j (where A_ii = 0, so it only contributes -k_i*k_i/2m).
+        for &i in &indices {
+            let k_i = degree[&i] as f64;
+            total += -(k_i * k_i) / two_m;
+        }
+
+        for (pos, &i) in indices.iter().enumerate() {
+            for &j in &indices[pos + 1..] {
+                let a_ij = if graph.find_edge(i, j).is_some() { 1.0 } else { 0.0 };
+                let k_i = degree[&i] as f64;
+                let k_j = degree[&j] as f64;
+                // An unordered pair stands in for the two ordered pairs (i, j) and
+                // (j, i) the textbook sum counts separately, hence the *2.
+                total += 2.0 * (a_ij - (k_i * k_j) / two_m);
             }
         }
     }
 
-    // If there are no distances, median is undefined
-    if distances.is_empty() {
-        return 0.0;
+    ModularityReport {
+        modularity: total / two_m,
+        coverage: covered.len() as f64 / graph.node_count() as f64,
     }
+}
 
-    // Sort distances to find the median
-    distances.sort_by(|a, b| a.partial_cmp(b).unwrap());
+/// Closeness centrality for every node: (reachable nodes - 1) / (sum of distances to
+/// them), restricted to each node's own component since unreachable nodes contribute
+/// neither to the count nor the sum. Reuses the same BFS sweep as the separation stats.
+pub fn closeness_centrality(graph: &Graph<String, (), Undirected>) -> HashMap<NodeIndex, f64> {
+    graph
+        .node_indices()
+        .map(|node| {
+            let reached: Vec<u32> = bfs_distances(graph, node)
+                .into_iter()
+                .flatten()
+                .filter(|&d| d > 0)
+                .collect();
+            let sum: u32 = reached.iter().sum();
+            let closeness = if sum > 0 { reached.len() as f64 / sum as f64 } else { 0.0 };
+            (node, closeness)
+        })
+        .collect()
+}
 
-    let mid = distances.len() / 2;
-    if distances.len() % 2 == 0 {
-        // Even number of elements: median is the average of the two middle values
-        (distances[mid - 1] + distances[mid]) / 2.0
-    } else {
-        // Odd number of elements: median is the middle value
-        distances[mid]
+/// Betweenness centrality via Brandes' algorithm: BFS from every source while tracking
+/// predecessors on shortest paths and the shortest-path count sigma[v] to each node,
+/// then accumulate dependencies delta[v] += (sigma[v]/sigma[w]) * (1 + delta[w]) back
+/// to front in order of decreasing distance. Totals are halved since the graph is
+/// undirected (every pair is its own source and target).
+pub fn betweenness_centrality(graph: &Graph<String, (), Undirected>) -> HashMap<NodeIndex, f64> {
+    let n = graph.node_count();
+    let mut betweenness = vec![0.0; n];
+
+    for s in graph.node_indices() {
+        let mut predecessors: Vec<Vec<NodeIndex>> = vec![vec![]; n];
+        let mut sigma = vec![0.0; n];
+        let mut dist: Vec<Option<u32>> = vec![None; n];
+        let mut order = vec![];
+        let mut queue = VecDeque::new();
+
+        sigma[s.index()] = 1.0;
+        dist[s.index()] = Some(0);
+        queue.push_back(s);
+
+        while let Some(v) = queue.pop_front() {
+            order.push(v);
+            let dist_v = dist[v.index()].unwrap();
+            for w in graph.neighbors(v) {
+                if dist[w.index()].is_none() {
+                    dist[w.index()] = Some(dist_v + 1);
+                    queue.push_back(w);
+                }
+                if dist[w.index()] == Some(dist_v + 1) {
+                    sigma[w.index()] += sigma[v.index()];
+                    predecessors[w.index()].push(v);
+                }
+            }
+        }
+
+        let mut delta = vec![0.0; n];
+        for &w in order.iter().rev() {
+            for &v in &predecessors[w.index()] {
+                delta[v.index()] += (sigma[v.index()] / sigma[w.index()]) * (1.0 + delta[w.index()]);
+            }
+            if w != s {
+                betweenness[w.index()] += delta[w.index()];
+            }
+        }
     }
+
+    graph
+        .node_indices()
+        .map(|node| (node, betweenness[node.index()] / 2.0))
+        .collect()
+}
+
+/// Ranks nodes by a centrality score, most central first, and maps them back to their
+/// original string ids.
+pub fn top_k_by_centrality(
+    graph: &Graph<String, (), Undirected>,
+    scores: &HashMap<NodeIndex, f64>,
+    k: usize,
+) -> Vec<(String, f64)> {
+    let mut ranked: Vec<(String, f64)> = scores
+        .iter()
+        .map(|(&node, &score)| (graph[node].clone(), score))
+        .collect();
+    ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+    ranked.truncate(k);
+    ranked
 }
 
 